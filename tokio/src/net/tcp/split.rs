@@ -8,11 +8,12 @@
 //! split has no associated overhead and enforces all invariants at the type
 //! level.
 
+use crate::future::poll_fn;
 use crate::io::{AsyncRead, AsyncWrite};
 use crate::net::TcpStream;
 
 use bytes::{Buf, BufMut};
-use std::io;
+use std::io::{self, IoSlice, IoSliceMut};
 use std::net::Shutdown;
 use std::pin::Pin;
 use std::task::{Context, Poll};
@@ -32,6 +33,44 @@ pub(crate) fn split(stream: &mut TcpStream) -> (ReadHalf<'_>, WriteHalf<'_>) {
     (ReadHalf(&*stream), WriteHalf(&*stream))
 }
 
+impl ReadHalf<'_> {
+    /// Attempts to receive data on the socket, without removing that data from
+    /// the queue, registering the current task for wakeup if the data is not
+    /// yet available.
+    ///
+    /// Successful peeks do not advance the socket's receive queue, so a
+    /// subsequent `poll_read` will observe the same bytes.
+    /// This lets a reader sniff a protocol magic number or TLS ClientHello
+    /// before dispatching to the real read loop.
+    pub fn poll_peek(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut dyn BufMut,
+    ) -> Poll<io::Result<usize>> {
+        self.0.poll_peek(cx, buf)
+    }
+
+    /// Receives data on the socket from the remote address to which it is
+    /// connected, without removing that data from the queue. On success,
+    /// returns the number of bytes peeked.
+    pub async fn peek(&mut self, buf: &mut dyn BufMut) -> io::Result<usize> {
+        poll_fn(|cx| self.poll_peek(cx, buf)).await
+    }
+
+    /// Reads into a set of buffers using a single `readv` syscall, filling each
+    /// slice in order before moving on to the next.
+    ///
+    /// This is the scatter counterpart to `poll_read` and lets a framed reader
+    /// assemble a message across several buffers without an extra copy.
+    pub fn poll_read_vectored(
+        &mut self,
+        cx: &mut Context<'_>,
+        bufs: &mut [IoSliceMut<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.0.poll_read_vectored_priv(cx, bufs)
+    }
+}
+
 impl AsyncRead for ReadHalf<'_> {
     fn poll_read(
         self: Pin<&mut Self>,
@@ -51,6 +90,27 @@ impl AsyncWrite for WriteHalf<'_> {
         self.0.poll_write_priv(cx, buf)
     }
 
+    /// Writes a set of buffers using a single `writev` syscall, consuming them
+    /// in order.
+    ///
+    /// This is the gather counterpart to `poll_write` and lets a framed writer
+    /// emit header + body + trailer buffers in one syscall.
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.0.poll_write_vectored_priv(cx, bufs)
+    }
+
+    /// Returns `true` if the underlying socket benefits from vectored writes.
+    ///
+    /// Callers can query this to decide whether to batch buffers into a
+    /// `poll_write_vectored` call or fall back to a single-buffer `poll_write`.
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
     #[inline]
     fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
         // tcp flush is a no-op
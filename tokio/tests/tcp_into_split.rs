@@ -0,0 +1,79 @@
+#![warn(rust_2018_idioms)]
+
+use tokio::future::poll_fn;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpListener;
+
+use bytes::{Bytes, BytesMut};
+use std::io;
+use std::net::TcpStream as StdTcpStream;
+use std::pin::Pin;
+
+async fn read_some(read: &mut (impl AsyncRead + Unpin), buf: &mut BytesMut) -> io::Result<usize> {
+    poll_fn(|cx| Pin::new(&mut *read).poll_read(cx, buf)).await
+}
+
+async fn write_some(write: &mut (impl AsyncWrite + Unpin), buf: &mut Bytes) -> io::Result<usize> {
+    poll_fn(|cx| Pin::new(&mut *write).poll_write(cx, buf)).await
+}
+
+#[tokio::test]
+async fn into_split_halves_are_send_and_move_into_separate_tasks() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let accept = tokio::spawn(async move { listener.accept().await.unwrap().0 });
+    let peer = tokio::task::spawn_blocking(move || StdTcpStream::connect(addr).unwrap())
+        .await
+        .unwrap();
+
+    let stream = accept.await.unwrap();
+    let (mut read_half, mut write_half) = stream.into_split();
+
+    // `OwnedReadHalf`/`OwnedWriteHalf` are `Send + 'static`, so each one can
+    // move into its own task instead of staying pinned to the task that split
+    // the stream.
+    let writer = tokio::spawn(async move {
+        let mut data = Bytes::from_static(b"ping");
+        while !data.is_empty() {
+            write_some(&mut write_half, &mut data).await.unwrap();
+        }
+    });
+    let reader = tokio::spawn(async move {
+        let mut buf = BytesMut::with_capacity(4);
+        while buf.len() < 4 {
+            read_some(&mut read_half, &mut buf).await.unwrap();
+        }
+        buf
+    });
+
+    writer.await.unwrap();
+
+    let mut received = [0u8; 4];
+    io::Read::read_exact(&mut &peer, &mut received).unwrap();
+    assert_eq!(&received, b"ping");
+    io::Write::write_all(&mut &peer, &received).unwrap();
+
+    let echoed = reader.await.unwrap();
+    assert_eq!(&echoed[..], b"ping");
+}
+
+#[tokio::test]
+async fn dropping_owned_write_half_shuts_down_write_direction() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let accept = tokio::spawn(async move { listener.accept().await.unwrap().0 });
+    let peer = tokio::task::spawn_blocking(move || StdTcpStream::connect(addr).unwrap())
+        .await
+        .unwrap();
+
+    let stream = accept.await.unwrap();
+    let (_read_half, write_half) = stream.into_split();
+
+    drop(write_half);
+
+    let mut buf = [0u8; 1];
+    let n = io::Read::read(&mut &peer, &mut buf).unwrap();
+    assert_eq!(n, 0, "peer should observe EOF once the write half is dropped");
+}
@@ -0,0 +1,42 @@
+#![warn(rust_2018_idioms)]
+
+use tokio::net::{TcpListener, TcpStream};
+
+#[tokio::test]
+async fn reunite_succeeds_for_halves_from_the_same_stream() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let accept = tokio::spawn(async move { listener.accept().await.unwrap().0 });
+    let _client = TcpStream::connect(addr).await.unwrap();
+    let stream = accept.await.unwrap();
+    let local_addr = stream.local_addr().unwrap();
+
+    let (read_half, write_half) = stream.into_split();
+    let reunited = read_half.reunite(write_half).unwrap();
+
+    assert_eq!(reunited.local_addr().unwrap(), local_addr);
+}
+
+#[tokio::test]
+async fn reunite_fails_for_halves_from_different_streams() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let accept = tokio::spawn(async move {
+        let a = listener.accept().await.unwrap().0;
+        let b = listener.accept().await.unwrap().0;
+        (a, b)
+    });
+    let _client_a = TcpStream::connect(addr).await.unwrap();
+    let _client_b = TcpStream::connect(addr).await.unwrap();
+    let (stream_a, stream_b) = accept.await.unwrap();
+
+    let (read_a, _write_a) = stream_a.into_split();
+    let (_read_b, write_b) = stream_b.into_split();
+
+    // Mismatched halves are handed back unchanged instead of being dropped.
+    let err = read_a.reunite(write_b).unwrap_err();
+    let _: tokio::net::tcp::OwnedReadHalf = err.0;
+    let _: tokio::net::tcp::OwnedWriteHalf = err.1;
+}
@@ -0,0 +1,39 @@
+#![warn(rust_2018_idioms)]
+
+use tokio::future::poll_fn;
+use tokio::io::AsyncRead;
+use tokio::net::TcpListener;
+
+use bytes::BytesMut;
+use std::io::Write;
+use std::net::TcpStream as StdTcpStream;
+use std::pin::Pin;
+
+#[tokio::test]
+async fn peek_leaves_bytes_in_the_receive_queue_for_poll_read() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let accept = tokio::spawn(async move { listener.accept().await.unwrap().0 });
+    let mut peer = tokio::task::spawn_blocking(move || StdTcpStream::connect(addr).unwrap())
+        .await
+        .unwrap();
+    peer.write_all(b"ping").unwrap();
+
+    let mut stream = accept.await.unwrap();
+    let (mut read_half, _write_half) = stream.split();
+
+    let mut peeked = BytesMut::with_capacity(4);
+    let n = read_half.peek(&mut peeked).await.unwrap();
+    assert_eq!(n, 4);
+    assert_eq!(&peeked[..], b"ping");
+
+    // The peeked bytes were not removed from the socket's receive queue, so a
+    // subsequent `poll_read` observes them again.
+    let mut read = BytesMut::with_capacity(4);
+    let n = poll_fn(|cx| Pin::new(&mut read_half).poll_read(cx, &mut read))
+        .await
+        .unwrap();
+    assert_eq!(n, 4);
+    assert_eq!(&read[..], b"ping");
+}
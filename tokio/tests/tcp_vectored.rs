@@ -0,0 +1,78 @@
+#![warn(rust_2018_idioms)]
+
+use tokio::future::poll_fn;
+use tokio::io::AsyncWrite;
+use tokio::net::TcpListener;
+
+use std::io::{IoSlice, IoSliceMut, Read, Write};
+use std::net::TcpStream as StdTcpStream;
+use std::pin::Pin;
+
+#[tokio::test]
+async fn write_half_is_write_vectored_reports_true() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let accept = tokio::spawn(async move { listener.accept().await.unwrap().0 });
+    let _peer = tokio::task::spawn_blocking(move || StdTcpStream::connect(addr).unwrap())
+        .await
+        .unwrap();
+
+    let mut stream = accept.await.unwrap();
+    let (_read_half, write_half) = stream.split();
+
+    assert!(write_half.is_write_vectored());
+}
+
+#[tokio::test]
+async fn write_vectored_sends_all_slices_in_one_call() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let accept = tokio::spawn(async move { listener.accept().await.unwrap().0 });
+    let mut peer = tokio::task::spawn_blocking(move || StdTcpStream::connect(addr).unwrap())
+        .await
+        .unwrap();
+
+    let mut stream = accept.await.unwrap();
+    let (_read_half, mut write_half) = stream.split();
+
+    let header = b"head:";
+    let body = b"body";
+    let bufs = [IoSlice::new(header), IoSlice::new(body)];
+
+    let n = poll_fn(|cx| Pin::new(&mut write_half).poll_write_vectored(cx, &bufs))
+        .await
+        .unwrap();
+    assert_eq!(n, header.len() + body.len());
+
+    let mut received = [0u8; 9];
+    peer.read_exact(&mut received).unwrap();
+    assert_eq!(&received, b"head:body");
+}
+
+#[tokio::test]
+async fn read_vectored_fills_slices_in_order() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let accept = tokio::spawn(async move { listener.accept().await.unwrap().0 });
+    let mut peer = tokio::task::spawn_blocking(move || StdTcpStream::connect(addr).unwrap())
+        .await
+        .unwrap();
+    peer.write_all(b"head:body").unwrap();
+
+    let mut stream = accept.await.unwrap();
+    let (mut read_half, _write_half) = stream.split();
+
+    let mut first = [0u8; 5];
+    let mut second = [0u8; 4];
+    let mut bufs = [IoSliceMut::new(&mut first), IoSliceMut::new(&mut second)];
+
+    let n = poll_fn(|cx| read_half.poll_read_vectored(cx, &mut bufs))
+        .await
+        .unwrap();
+    assert_eq!(n, 9);
+    assert_eq!(&first, b"head:");
+    assert_eq!(&second, b"body");
+}